@@ -2,11 +2,18 @@ use clap::Parser;
 use walkdir::WalkDir;
 use rayon::prelude::*;
 use rexif::{parse_buffer, ExifTag};
-use std::collections::HashSet;
+use regex::Regex;
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::io::Read;
+use std::io::{Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Mutex;
+use std::sync::OnceLock;
 use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
 
 #[derive(Parser, Debug)]
@@ -23,21 +30,221 @@ struct Args {
     /// Read the entire file to find EXIF data. Slower but more reliable.
     #[arg(short, long, default_value_t = false)]
     full_scan: bool,
+
+    /// Sort into a YYYY/MM/DD directory tree instead of a flat output directory.
+    #[arg(short, long, default_value_t = false)]
+    tree: bool,
+
+    /// Additional file extensions to include besides jpg/jpeg, comma-separated
+    /// (e.g. mov,mp4,heic,png,cr2).
+    #[arg(long, value_delimiter = ',')]
+    extensions: Vec<String>,
+
+    /// Shell out to `exiftool` for files rexif can't parse, such as video and
+    /// non-JPEG image formats. Requires `exiftool` to be on PATH.
+    #[arg(long, default_value_t = false)]
+    use_exiftool: bool,
+
+    /// Copy files instead of moving them, e.g. for cross-filesystem output
+    /// directories or to keep the originals. A collision with a byte-identical
+    /// file is skipped; any other collision gets a new name, as without --copy.
+    #[arg(short, long, default_value_t = false)]
+    copy: bool,
+
+    /// Plan the sort but don't touch any files; print the planned
+    /// source -> destination mapping instead of Phase 3's I/O.
+    #[arg(long, default_value_t = false)]
+    dry_run: bool,
+
+    /// Write a JSON report of planned moves, skipped files, and summary
+    /// counts to this path.
+    #[arg(long)]
+    report: Option<PathBuf>,
+
+    /// Flag JPEGs with truncated or structurally invalid image data (e.g. a
+    /// missing end-of-image marker) instead of trusting a successful EXIF
+    /// parse alone.
+    #[arg(long, default_value_t = false)]
+    verify: bool,
+
+    /// With --verify, move flagged files into a `broken/` subdirectory
+    /// instead of sorting them alongside healthy photos.
+    #[arg(long, default_value_t = false)]
+    quarantine_broken: bool,
+}
+
+#[derive(Serialize)]
+struct PlannedMoveReport {
+    source: PathBuf,
+    dest: PathBuf,
+}
+
+#[derive(Serialize)]
+struct SkippedFileReport {
+    source: PathBuf,
+    reason: String,
+}
+
+#[derive(Serialize)]
+struct BrokenFileReport {
+    source: PathBuf,
+    reason: &'static str,
+}
+
+#[derive(Serialize)]
+struct Report {
+    planned_moves: Vec<PlannedMoveReport>,
+    skipped: Vec<SkippedFileReport>,
+    broken: Vec<BrokenFileReport>,
+    date_sources: HashMap<&'static str, usize>,
+    unknown_date: usize,
+    skipped_identical: usize,
+    renamed_for_collision: usize,
 }
 
-fn get_date_taken(path: &Path, full_scan: bool) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+/// Hashes a file's contents with SHA-256, for content-aware collision checks.
+fn hash_file(path: &Path) -> Option<[u8; 32]> {
+    let mut file = fs::File::open(path).ok()?;
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut file, &mut hasher).ok()?;
+    Some(hasher.finalize().into())
+}
+
+/// Whether `a` and `b` are byte-identical, used to tell a genuine name
+/// collision apart from the same photo already having been sorted before.
+fn files_are_identical(a: &Path, b: &Path) -> bool {
+    matches!((hash_file(a), hash_file(b)), (Some(ha), Some(hb)) if ha == hb)
+}
+
+/// Picks a collision-free destination for `source_path` inside `dir`, named
+/// `<stem>.<ext>` or `<stem>_<n>.<ext>` on collision. Under `--copy`, a
+/// collision with byte-identical content is reported back as `None` (skip)
+/// rather than given a new name. Returns the chosen path and how many
+/// renames it took, so callers can tally collisions.
+fn plan_destination(
+    source_path: &Path,
+    dir: &Path,
+    stem: &str,
+    ext: &str,
+    dir_used_names: &mut HashSet<String>,
+    use_copy: bool,
+) -> Option<(PathBuf, usize)> {
+    let mut counter = 0;
+    loop {
+        let out_name = if counter == 0 {
+            format!("{}.{}", stem, ext)
+        } else {
+            format!("{}_{}.{}", stem, counter, ext)
+        };
+        let dest_path_candidate = dir.join(&out_name);
+        let collides = dest_path_candidate.exists();
+        if !collides && dir_used_names.insert(out_name) {
+            return Some((dest_path_candidate, counter));
+        }
+        if use_copy && collides && files_are_identical(source_path, &dest_path_candidate) {
+            return None;
+        }
+        counter += 1;
+    }
+}
+
+/// Routes a batch of `files` into `dir` (creating it if needed, unless
+/// `dry_run`), planning a collision-free destination for each via
+/// [`plan_destination`]. Used for the `unknown/` and `broken/` catch-all
+/// directories, where files keep the stem and extension of their source name
+/// and collisions are tallied as in the main dated-output loop. If `dir`
+/// can't be created, every file in the batch is recorded in `failed_files`
+/// with the same reason instead.
+#[allow(clippy::too_many_arguments)]
+fn route_files_to_subdir(
+    dir: &Path,
+    files: Vec<PathBuf>,
+    used_names: &mut HashMap<PathBuf, HashSet<String>>,
+    use_copy: bool,
+    dry_run: bool,
+    planned_moves: &mut Vec<(PathBuf, PathBuf)>,
+    skipped_identical: &mut usize,
+    renamed_for_collision: &mut usize,
+    failed_files: &Mutex<Vec<(PathBuf, String)>>,
+) {
+    if !dry_run {
+        if let Err(e) = fs::create_dir_all(dir) {
+            let reason = format!("Could not create {:?}: {}", dir, e);
+            let mut failed_files = failed_files.lock().unwrap();
+            for source_path in files {
+                failed_files.push((source_path, reason.clone()));
+            }
+            return;
+        }
+    }
+
+    let dir_used_names = used_names.entry(dir.to_path_buf()).or_default();
+    for source_path in files {
+        let stem = source_path.file_stem().and_then(|s| s.to_str()).unwrap_or("file").to_string();
+        let ext = source_path.extension().and_then(|s| s.to_str()).unwrap_or("jpg").to_string();
+        match plan_destination(&source_path, dir, &stem, &ext, dir_used_names, use_copy) {
+            Some((dest_path, counter)) => {
+                if counter > 0 {
+                    *renamed_for_collision += 1;
+                }
+                planned_moves.push((source_path, dest_path));
+            }
+            None => *skipped_identical += 1,
+        }
+    }
+}
+
+/// Splits an EXIF `DateTimeOriginal` string (`YYYY:MM:DD HH:MM:SS`) into its
+/// year, month and day components.
+fn split_date_components(date_str: &str) -> Option<(&str, &str, &str)> {
+    let date_part = date_str.split(' ').next()?;
+    let mut fields = date_part.split(':');
+    let year = fields.next()?;
+    let month = fields.next()?;
+    let day = fields.next()?;
+    Some((year, month, day))
+}
+
+/// Where a resolved capture date ultimately came from, in fallback order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateSource {
+    Exif,
+    Exiftool,
+    Filename,
+    FolderPath,
+    FileSystemTime,
+}
+
+impl DateSource {
+    fn label(&self) -> &'static str {
+        match self {
+            DateSource::Exif => "EXIF DateTimeOriginal",
+            DateSource::Exiftool => "exiftool CreateDate",
+            DateSource::Filename => "filename pattern",
+            DateSource::FolderPath => "folder path (YYYY/MM)",
+            DateSource::FileSystemTime => "filesystem timestamp",
+        }
+    }
+}
+
+/// Reads a JPEG's header bytes: the whole file under `--full-scan`, or just
+/// the first 64KB (usually enough for EXIF data) otherwise.
+fn read_jpeg_header(path: &Path, full_scan: bool) -> std::io::Result<Vec<u8>> {
     let mut file = fs::File::open(path)?;
-    let exif = if full_scan {
+    if full_scan {
         let mut buffer = Vec::new();
         file.read_to_end(&mut buffer)?;
-        parse_buffer(&buffer)?
+        Ok(buffer)
     } else {
-        // Read only the first 64KB, which is usually enough for EXIF data.
         let mut buffer = vec![0; 64 * 1024];
         let n = file.read(&mut buffer)?;
-        parse_buffer(&buffer[..n])?
-    };
+        buffer.truncate(n);
+        Ok(buffer)
+    }
+}
 
+fn get_date_from_exif(header: &[u8]) -> Result<String, Box<dyn std::error::Error + Send + Sync>> {
+    let exif = parse_buffer(header)?;
     for entry in exif.entries {
         if entry.tag == ExifTag::DateTimeOriginal {
             return Ok(entry.value.to_string());
@@ -46,6 +253,168 @@ fn get_date_taken(path: &Path, full_scan: bool) -> Result<String, Box<dyn std::e
     Err("Could not find DateTimeOriginal EXIF tag".into())
 }
 
+/// Why `--verify` flagged a file as broken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BrokenReason {
+    Truncated,
+    MissingSoiMarker,
+    MissingEoiMarker,
+}
+
+impl BrokenReason {
+    fn label(&self) -> &'static str {
+        match self {
+            BrokenReason::Truncated => "truncated",
+            BrokenReason::MissingSoiMarker => "missing SOI marker",
+            BrokenReason::MissingEoiMarker => "missing EOI marker",
+        }
+    }
+}
+
+/// Flags a JPEG as broken if it's missing the `FFD8` start-of-image or
+/// `FFD9` end-of-image marker, or is too short to be a valid JPEG. Reuses
+/// `header` (already read for EXIF parsing) for the start marker, and under
+/// `full_scan`, where it holds the whole file, for the end marker too;
+/// otherwise does a cheap seek-to-end read for just the trailing two bytes.
+fn verify_jpeg_integrity(path: &Path, header: &[u8], full_scan: bool) -> Option<BrokenReason> {
+    if header.len() < 4 {
+        return Some(BrokenReason::Truncated);
+    }
+
+    if header[0..2] != [0xFF, 0xD8] {
+        return Some(BrokenReason::MissingSoiMarker);
+    }
+
+    let tail: [u8; 2] = if full_scan {
+        let len = header.len();
+        [header[len - 2], header[len - 1]]
+    } else {
+        let mut file = fs::File::open(path).ok()?;
+        let len = file.metadata().ok()?.len();
+        if len < 4 {
+            return Some(BrokenReason::Truncated);
+        }
+        file.seek(SeekFrom::End(-2)).ok()?;
+        let mut tail = [0u8; 2];
+        file.read_exact(&mut tail).ok()?;
+        tail
+    };
+
+    if tail == [0xFF, 0xD9] {
+        None
+    } else {
+        Some(BrokenReason::MissingEoiMarker)
+    }
+}
+
+#[derive(Deserialize)]
+struct ExiftoolEntry {
+    #[serde(rename = "CreateDate")]
+    create_date: Option<String>,
+}
+
+/// Shells out to `exiftool -json -CreateDate` for formats `rexif` can't read
+/// (video, HEIC, RAW, etc.).
+fn get_date_from_exiftool(path: &Path) -> Option<String> {
+    let output = Command::new("exiftool")
+        .arg("-json")
+        .arg("-CreateDate")
+        .arg(path)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let entries: Vec<ExiftoolEntry> = serde_json::from_slice(&output.stdout).ok()?;
+    entries.into_iter().next()?.create_date
+}
+
+/// Pulls a timestamp out of common camera/phone filename conventions, e.g.
+/// `IMG_20231105_143022.jpg` or `2023-11-05_beach.jpg`. Regexes are compiled
+/// once and cached, since this runs once per file in the Phase 1 `par_iter`.
+fn get_date_from_filename(path: &Path) -> Option<String> {
+    static IMG_TIMESTAMP_RE: OnceLock<Regex> = OnceLock::new();
+    static DATE_RE: OnceLock<Regex> = OnceLock::new();
+
+    let name = path.file_name()?.to_str()?;
+
+    let img_timestamp_re = IMG_TIMESTAMP_RE
+        .get_or_init(|| Regex::new(r"IMG_(\d{4})(\d{2})(\d{2})_(\d{2})(\d{2})(\d{2})").unwrap());
+    if let Some(caps) = img_timestamp_re.captures(name) {
+        return Some(format!(
+            "{}:{}:{} {}:{}:{}",
+            &caps[1], &caps[2], &caps[3], &caps[4], &caps[5], &caps[6]
+        ));
+    }
+
+    let date_re = DATE_RE.get_or_init(|| Regex::new(r"(\d{4})-(\d{2})-(\d{2})").unwrap());
+    if let Some(caps) = date_re.captures(name) {
+        return Some(format!("{}:{}:{} 00:00:00", &caps[1], &caps[2], &caps[3]));
+    }
+
+    None
+}
+
+/// Falls back to a `YYYY/MM` segment in the file's containing directories.
+fn get_date_from_folder_path(path: &Path) -> Option<String> {
+    static FOLDER_DATE_RE: OnceLock<Regex> = OnceLock::new();
+
+    let path_str = path.parent()?.to_str()?;
+    let folder_date_re =
+        FOLDER_DATE_RE.get_or_init(|| Regex::new(r"(\d{4})[/\\](\d{2})(?:[/\\]|$)").unwrap());
+    let caps = folder_date_re.captures(path_str)?;
+    Some(format!("{}:{}:01 00:00:00", &caps[1], &caps[2]))
+}
+
+/// Last resort: the file's modification time (falling back to creation time).
+fn get_date_from_metadata(path: &Path) -> Option<String> {
+    let metadata = fs::metadata(path).ok()?;
+    let system_time = metadata.modified().or_else(|_| metadata.created()).ok()?;
+    let datetime: DateTime<Local> = system_time.into();
+    Some(datetime.format("%Y:%m:%d %H:%M:%S").to_string())
+}
+
+fn is_jpeg(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("jpg") || ext.eq_ignore_ascii_case("jpeg"))
+}
+
+/// A resolved capture date, plus integrity info from `--verify`.
+struct DateResolution {
+    date_str: String,
+    source: DateSource,
+    broken: Option<BrokenReason>,
+}
+
+/// Resolves a capture date for `path`, trying EXIF first and falling back
+/// through `exiftool` (if enabled), the filename, containing folder, and
+/// filesystem timestamp before giving up.
+fn get_date_taken(path: &Path, full_scan: bool, use_exiftool: bool, verify: bool) -> Result<DateResolution, Box<dyn std::error::Error + Send + Sync>> {
+    let header = is_jpeg(path).then(|| read_jpeg_header(path, full_scan).ok()).flatten();
+    let broken = if verify {
+        header.as_deref().and_then(|h| verify_jpeg_integrity(path, h, full_scan))
+    } else {
+        None
+    };
+
+    if let Some(date_str) = header.as_deref().and_then(|h| get_date_from_exif(h).ok()) {
+        return Ok(DateResolution { date_str, source: DateSource::Exif, broken });
+    }
+    if let Some(date_str) = use_exiftool.then(|| get_date_from_exiftool(path)).flatten() {
+        return Ok(DateResolution { date_str, source: DateSource::Exiftool, broken });
+    }
+    if let Some(date_str) = get_date_from_filename(path) {
+        return Ok(DateResolution { date_str, source: DateSource::Filename, broken });
+    }
+    if let Some(date_str) = get_date_from_folder_path(path) {
+        return Ok(DateResolution { date_str, source: DateSource::FolderPath, broken });
+    }
+    if let Some(date_str) = get_date_from_metadata(path) {
+        return Ok(DateResolution { date_str, source: DateSource::FileSystemTime, broken });
+    }
+    Err(format!("Could not resolve a date for {:?} from any source", path).into())
+}
+
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args = Args::parse();
 
@@ -61,13 +430,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     fs::create_dir_all(&args.out_dir)?;
 
+    let extra_extensions: HashSet<String> = args.extensions.iter().map(|e| e.to_lowercase()).collect();
+
     let walker = WalkDir::new(&args.in_dir).into_iter().filter_map(|e| e.ok());
 
-    let jpeg_files: Vec<_> = walker
+    let input_files: Vec<_> = walker
         .filter(|entry| {
             entry.file_type().is_file()
-                && entry.path().extension().map_or(false, |ext| {
-                    ext.eq_ignore_ascii_case("jpg") || ext.eq_ignore_ascii_case("jpeg")
+                && entry.path().extension().is_some_and(|ext| {
+                    let ext = ext.to_string_lossy().to_lowercase();
+                    ext == "jpg" || ext == "jpeg" || extra_extensions.contains(&ext)
                 })
         })
         .map(|entry| entry.into_path())
@@ -78,76 +450,210 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({percent}%) {msg}")?
         .progress_chars("#>");
 
-    let failed_files = Mutex::new(Vec::new());
+    let failed_files: Mutex<Vec<(PathBuf, String)>> = Mutex::new(Vec::new());
 
     // --- Phase 1: Parallel EXIF Parsing ---
-    let pb1 = multi_pb.add(ProgressBar::new(jpeg_files.len() as u64));
+    let pb1 = multi_pb.add(ProgressBar::new(input_files.len() as u64));
     pb1.set_style(style.clone());
     pb1.set_message("Parsing files");
 
-    let parsed_data: Vec<(PathBuf, String)> = jpeg_files
-        .par_iter()
-        .filter_map(|path| {
-            let result = match get_date_taken(path, args.full_scan) {
-                Ok(date_str) => Some((path.clone(), date_str)),
-                Err(e) => {
-                    let error_msg = format!("Skipping {:?}: Could not get date taken - {}", path, e);
-                    failed_files.lock().unwrap().push(error_msg);
-                    None
-                }
-            };
-            pb1.inc(1);
-            result
-        })
-        .collect();
+    let resolved_files = Mutex::new(Vec::new());
+    let unknown_files = Mutex::new(Vec::new());
+
+    input_files.par_iter().for_each(|path| {
+        match get_date_taken(path, args.full_scan, args.use_exiftool, args.verify) {
+            Ok(resolution) => resolved_files.lock().unwrap().push((path.clone(), resolution)),
+            Err(_) => unknown_files.lock().unwrap().push(path.clone()),
+        }
+        pb1.inc(1);
+    });
+
+    let resolved_files = resolved_files.into_inner().unwrap();
+    let unknown_files = unknown_files.into_inner().unwrap();
+    let unknown_count = unknown_files.len();
 
     pb1.finish_with_message("Parsing complete!");
 
+    // Files flagged by --verify are reported separately and, with
+    // --quarantine-broken, routed to broken/ instead of the dated output.
+    let mut broken_reports: Vec<BrokenFileReport> = Vec::new();
+    let mut quarantine_files: Vec<PathBuf> = Vec::new();
+    let mut parsed_data: Vec<(PathBuf, String, DateSource)> = Vec::new();
+    for (path, resolution) in resolved_files {
+        if let Some(reason) = resolution.broken {
+            broken_reports.push(BrokenFileReport { source: path.clone(), reason: reason.label() });
+            if args.quarantine_broken {
+                quarantine_files.push(path);
+                continue;
+            }
+        }
+        parsed_data.push((path, resolution.date_str, resolution.source));
+    }
+
+    let mut source_counts: HashMap<&'static str, usize> = HashMap::new();
+    for (_, _, source) in &parsed_data {
+        *source_counts.entry(source.label()).or_insert(0) += 1;
+    }
+
     // --- Phase 2: Sequential Destination Planning ---
     let mut planned_moves = Vec::new();
-    let mut used_names = HashSet::new();
-    for (source_path, date_str) in parsed_data {
-        let base_name = date_str.replace(':', "-").replace(' ', "_");
-        let mut counter = 0;
-        let dest_path = loop {
-            let out_name = if counter == 0 {
-                format!("{}.jpg", base_name)
-            } else {
-                format!("{}_{}.jpg", base_name, counter)
-            };
-            let dest_path_candidate = args.out_dir.join(&out_name);
-            if !dest_path_candidate.exists() && used_names.insert(out_name) {
-                break dest_path_candidate;
+    let mut used_names: HashMap<PathBuf, HashSet<String>> = HashMap::new();
+    let mut skipped_identical = 0usize;
+    let mut renamed_for_collision = 0usize;
+    for (source_path, date_str, _source) in parsed_data {
+        let leaf_dir = if args.tree {
+            match split_date_components(&date_str) {
+                Some((year, month, day)) => {
+                    let dir = args.out_dir.join(year).join(month).join(day);
+                    if !args.dry_run {
+                        if let Err(e) = fs::create_dir_all(&dir) {
+                            let reason = format!("Could not create tree directory {:?} - {}", dir, e);
+                            failed_files.lock().unwrap().push((source_path, reason));
+                            continue;
+                        }
+                    }
+                    dir
+                }
+                None => {
+                    let reason = format!("Could not parse date {:?} into year/month/day", date_str);
+                    failed_files.lock().unwrap().push((source_path, reason));
+                    continue;
+                }
             }
-            counter += 1;
+        } else {
+            args.out_dir.clone()
         };
-        planned_moves.push((source_path, dest_path));
+
+        let base_name = date_str.replace(':', "-").replace(' ', "_");
+        let dir_used_names = used_names.entry(leaf_dir.clone()).or_default();
+        match plan_destination(&source_path, &leaf_dir, &base_name, "jpg", dir_used_names, args.copy) {
+            Some((dest_path, counter)) => {
+                if counter > 0 {
+                    renamed_for_collision += 1;
+                }
+                planned_moves.push((source_path, dest_path));
+            }
+            None => skipped_identical += 1,
+        }
+    }
+
+    // Files for which every fallback failed still get moved, just into
+    // `unknown/`, so nothing is silently left behind in the input directory.
+    if !unknown_files.is_empty() {
+        let unknown_dir = args.out_dir.join("unknown");
+        route_files_to_subdir(
+            &unknown_dir,
+            unknown_files,
+            &mut used_names,
+            args.copy,
+            args.dry_run,
+            &mut planned_moves,
+            &mut skipped_identical,
+            &mut renamed_for_collision,
+            &failed_files,
+        );
+    }
+
+    // Files --verify flagged as broken, when --quarantine-broken routes them
+    // to broken/ instead of sorting them alongside healthy photos.
+    if !quarantine_files.is_empty() {
+        let broken_dir = args.out_dir.join("broken");
+        route_files_to_subdir(
+            &broken_dir,
+            quarantine_files,
+            &mut used_names,
+            args.copy,
+            args.dry_run,
+            &mut planned_moves,
+            &mut skipped_identical,
+            &mut renamed_for_collision,
+            &failed_files,
+        );
     }
 
     // --- Phase 3: Parallel I/O Execution ---
-    let pb2 = multi_pb.add(ProgressBar::new(planned_moves.len() as u64));
-    pb2.set_style(style);
-    pb2.set_message("Moving files");
-
-    planned_moves
-        .par_iter()
-        .for_each(|(source_path, dest_path)| {
-            if let Err(e) = fs::rename(source_path, dest_path) {
-                let error_msg = format!("Failed to rename {:?}: {}", source_path, e);
-                failed_files.lock().unwrap().push(error_msg);
-            }
-            pb2.inc(1);
-        });
+    let copied_count = AtomicUsize::new(0);
 
-    pb2.finish_with_message("Done!");
+    if args.dry_run {
+        println!("\n--- Dry Run: Planned Moves ({} total) ---", planned_moves.len());
+        for (source_path, dest_path) in &planned_moves {
+            println!("{:?} -> {:?}", source_path, dest_path);
+        }
+    } else {
+        let pb2 = multi_pb.add(ProgressBar::new(planned_moves.len() as u64));
+        pb2.set_style(style);
+        pb2.set_message("Moving files");
+
+        planned_moves
+            .par_iter()
+            .for_each(|(source_path, dest_path)| {
+                let result = if args.copy {
+                    fs::copy(source_path, dest_path).map(|_| ())
+                } else {
+                    fs::rename(source_path, dest_path)
+                };
+                match result {
+                    Ok(()) => {
+                        copied_count.fetch_add(1, Ordering::Relaxed);
+                    }
+                    Err(e) => {
+                        let verb = if args.copy { "copy" } else { "rename" };
+                        let reason = format!("Failed to {}: {}", verb, e);
+                        failed_files.lock().unwrap().push((source_path.clone(), reason));
+                    }
+                }
+                pb2.inc(1);
+            });
+
+        pb2.finish_with_message("Done!");
+    }
+
+    println!("\n--- Date Resolution Summary ---");
+    for (label, count) in &source_counts {
+        println!("{}: {}", label, count);
+    }
+
+    if args.copy {
+        println!("\n--- Copy Summary ---");
+        println!("copied: {}", copied_count.load(Ordering::Relaxed));
+        println!("skipped (already present, identical): {}", skipped_identical);
+        println!("renamed to avoid collision: {}", renamed_for_collision);
+    }
+
+    if args.verify && !broken_reports.is_empty() {
+        println!("\n--- Integrity Summary ---");
+        for report in &broken_reports {
+            println!("{:?}: {}", report.source, report.reason);
+        }
+    }
 
     let final_failed_files = failed_files.into_inner().unwrap();
     if !final_failed_files.is_empty() {
         eprintln!("\n--- Summary of Errors ---");
-        for error in final_failed_files {
-            eprintln!("{}", error);
+        for (path, reason) in &final_failed_files {
+            eprintln!("{:?}: {}", path, reason);
         }
     }
 
+    if let Some(report_path) = &args.report {
+        let report = Report {
+            planned_moves: planned_moves
+                .into_iter()
+                .map(|(source, dest)| PlannedMoveReport { source, dest })
+                .collect(),
+            skipped: final_failed_files
+                .into_iter()
+                .map(|(source, reason)| SkippedFileReport { source, reason })
+                .collect(),
+            broken: broken_reports,
+            date_sources: source_counts,
+            unknown_date: unknown_count,
+            skipped_identical,
+            renamed_for_collision,
+        };
+        let report_json = serde_json::to_string_pretty(&report)?;
+        fs::write(report_path, report_json)?;
+    }
+
     Ok(())
 }